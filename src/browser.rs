@@ -0,0 +1,144 @@
+use anyhow::{anyhow, Result};
+use selenium_manager::get_manager_by_driver;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use thirtyfour::{Capabilities, DesiredCapabilities, FirefoxPreferences, Proxy};
+
+use crate::{Configuration, USER_AGENT};
+
+/// The user-agent and proxy a single signup session should present, picked fresh
+/// per account so a batch doesn't share one fingerprint/IP.
+pub struct BrowserProfile {
+    pub user_agent: String,
+    pub proxy: Option<String>,
+}
+
+impl BrowserProfile {
+    /// Round-robins through `config.user_agents`/`config.proxies` by account index,
+    /// falling back to the default user-agent and no proxy when a list is empty.
+    pub fn for_account(config: &Configuration, index: usize) -> Self {
+        let user_agent = if config.user_agents.is_empty() {
+            USER_AGENT.to_string()
+        } else {
+            config.user_agents[index % config.user_agents.len()].clone()
+        };
+
+        let proxy = if config.proxies.is_empty() {
+            None
+        } else {
+            Some(config.proxies[index % config.proxies.len()].clone())
+        };
+
+        Self { user_agent, proxy }
+    }
+}
+
+/// The browser backend used to drive signups, selected via `Configuration::browser`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Browser {
+    Chrome,
+    Firefox,
+}
+
+impl Browser {
+    pub fn from_config(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "chrome" => Ok(Self::Chrome),
+            "firefox" => Ok(Self::Firefox),
+            other => Err(anyhow!("unsupported browser '{}', expected 'chrome' or 'firefox'", other)),
+        }
+    }
+
+    fn driver_name(&self) -> &'static str {
+        match self {
+            Self::Chrome => "chromedriver",
+            Self::Firefox => "geckodriver",
+        }
+    }
+
+    pub fn resolve_driver_path(&self) -> Result<String> {
+        let mut selenium_manager = get_manager_by_driver(self.driver_name().to_string()).unwrap();
+
+        let path = selenium_manager.resolve_driver().unwrap();
+
+        Ok(path.as_os_str().to_str().unwrap().to_string())
+    }
+
+    /// Builds the (unspawned) driver command for `port`, with stdout piped so the
+    /// caller can watch for the driver's readiness line.
+    pub fn driver_command(&self, driver_path: &str, port: u16) -> Command {
+        let mut command = Command::new(driver_path);
+
+        command
+            .arg("--ip=localhost")
+            .arg(format!("--port={}", port))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+
+        command
+    }
+
+    pub fn build_capabilities(
+        &self,
+        config: &Configuration,
+        profile: &BrowserProfile,
+        profile_dir: &Path,
+    ) -> Result<Capabilities> {
+        match self {
+            Self::Chrome => {
+                let mut caps = DesiredCapabilities::chrome();
+
+                caps.add_chrome_arg("--window-size=1920,1080")?;
+                caps.add_chrome_arg("--start-maximized")?;
+                caps.add_chrome_arg(format!("--user-agent={}", profile.user_agent).as_str())?;
+                caps.add_chrome_arg(
+                    format!("--user-data-dir={}", profile_dir.display()).as_str(),
+                )?;
+
+                if let Some(proxy) = &profile.proxy {
+                    caps.add_chrome_arg(format!("--proxy-server={}", proxy).as_str())?;
+                }
+
+                if config.headless.unwrap_or(true) {
+                    caps.add_chrome_arg("--headless")?;
+                }
+
+                Ok(caps.into())
+            }
+            Self::Firefox => {
+                let mut caps = DesiredCapabilities::firefox();
+                let mut prefs = FirefoxPreferences::new();
+
+                prefs.set_user_agent(profile.user_agent.clone())?;
+                caps.set_preferences(prefs)?;
+                caps.add_firefox_arg("-profile")?;
+                caps.add_firefox_arg(profile_dir.to_string_lossy().as_ref())?;
+
+                if let Some(proxy) = &profile.proxy {
+                    caps.set_proxy(Proxy::Manual {
+                        ftp_proxy: None,
+                        http_proxy: Some(proxy.clone()),
+                        ssl_proxy: Some(proxy.clone()),
+                        socks_proxy: None,
+                        socks_version: None,
+                        socks_username: None,
+                        socks_password: None,
+                        no_proxy: None,
+                    })?;
+                }
+
+                if config.headless.unwrap_or(true) {
+                    caps.set_headless()?;
+                }
+
+                Ok(caps.into())
+            }
+        }
+    }
+}
+
+impl Default for Browser {
+    fn default() -> Self {
+        Self::Chrome
+    }
+}
@@ -1,22 +1,30 @@
+mod browser;
+mod locale;
+mod process;
+mod profile;
+
 use anyhow::Result;
 use config::Config;
 use rand::{distributions::Uniform, thread_rng, Rng};
-use selenium_manager::get_manager_by_driver;
-use serde::Deserialize;
-use std::{fs, io::Write, path::PathBuf, process::Child, time::Instant};
+use serde::{Deserialize, Serialize};
+use std::{fs, io::Write, path::PathBuf, sync::Arc, time::Instant};
+use tokio::sync::{mpsc, Semaphore};
 
 use anyhow::anyhow;
 use chrono::{DateTime, Local};
-use thirtyfour::{prelude::*, ChromeCapabilities};
+use thirtyfour::prelude::*;
 use tracing::{error, info};
 
+pub use browser::{Browser, BrowserProfile};
+use locale::{Identity, Locale};
+use process::DriverProcess;
+use profile::TempProfile;
+
 const DOORDASH_URL: &str = "https://identity.doordash.com/auth/user/signup?client_id=1666519390426295040&enable_last_social=false&intl=en-US&layout=consumer_web&prompt=none&redirect_uri=https%3A%2F%2Fwww.doordash.com%2Fpost-login%2F&response_type=code&scope=%2A&state=%2Fen-US%2Fhome%2F%7C%7Cf0e073b3-2117-4d5e-9129-f5254065cdf3";
 const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/96.0.4664.110 Safari/537.36";
 
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, Clone)]
 pub struct Configuration {
-    first_name: String,
-    last_name: String,
     email_name: String,
     email_domain: String,
     address: String,
@@ -25,20 +33,37 @@ pub struct Configuration {
     quantity: Option<u32>,
     pub save_to_file: Option<bool>,
     headless: Option<bool>,
-    #[serde(default = "default_port")]
-    chromedriver_port: i32,
+    #[serde(default = "default_port_range")]
+    port_range: (u16, u16),
+    #[serde(default = "default_browser")]
+    browser: String,
+    #[serde(default = "default_concurrency")]
+    concurrency: usize,
+    #[serde(default)]
+    proxies: Vec<String>,
+    #[serde(default)]
+    user_agents: Vec<String>,
+    #[serde(default = "default_locale")]
+    locale: String,
+    #[serde(default = "default_output_format")]
+    output_format: String,
 }
 
-#[derive(Default)]
 pub struct AccountGenerator {
     pub config: Configuration,
     pub accounts: Vec<Account>,
-    caps: ChromeCapabilities,
+    browser: Browser,
+    locale: Locale,
 }
 
+#[derive(Serialize)]
 pub struct Account {
     pub email: String,
     pub password: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub phone_number: String,
+    pub locale: String,
     pub created: DateTime<Local>,
 }
 
@@ -48,61 +73,111 @@ impl AccountGenerator {
             tracing_subscriber::fmt::init();
         }
 
-        let mut self_ = Self {
-            ..Default::default()
-        };
-
-        self_.config = Self::load_config(config_path)?;
-        self_.caps = Self::get_caps(&self_)?;
+        let config = Self::load_config(config_path)?;
+        let browser = Browser::from_config(&config.browser)?;
+        let locale = Locale::from_config(&config.locale)?;
 
-        Ok(self_)
+        Ok(Self {
+            config,
+            accounts: Vec::new(),
+            browser,
+            locale,
+        })
     }
 
     #[tokio::main]
     pub async fn run(&mut self) -> Result<()> {
-        info!("Starting chromedriver...");
-        let driver_path = tokio::task::spawn_blocking(Self::get_driver_path).await??;
-        let mut chromedriver = self.run_chromedriver(driver_path)?;
+        info!("Starting {:?} driver...", self.browser);
+        let driver_path = {
+            let browser = self.browser;
+            tokio::task::spawn_blocking(move || browser.resolve_driver_path()).await??
+        };
+
+        let driver_process = {
+            let browser = self.browser;
+            let port_range = self.config.port_range;
+            tokio::task::spawn_blocking(move || {
+                DriverProcess::launch(browser, &driver_path, port_range)
+            })
+            .await??
+        };
 
         info!(
-            "chromedriver running on port {}",
-            &self.config.chromedriver_port
+            "{:?} driver running on port {}",
+            self.browser, driver_process.port
         );
 
         let quantity = self.config.quantity.unwrap_or(1);
+        let concurrency = self.config.concurrency.max(1);
 
-        for i in 0..quantity {
-            let start = Instant::now();
+        info!(
+            "Generating {} account(s) with {} concurrent worker(s)...",
+            quantity, concurrency
+        );
 
-            info!("Creating account {} of {}...", i + 1, quantity);
+        let server_url = Arc::new(format!("http://localhost:{}", driver_process.port));
+        let config = Arc::new(self.config.clone());
+        let semaphore = Arc::new(Semaphore::new(concurrency));
+        let (tx, mut rx) = mpsc::unbounded_channel();
 
-            let driver = WebDriver::new(
-                format!("http://localhost:{}", self.config.chromedriver_port).as_str(),
-                self.caps.clone(),
-            )
-            .await?;
+        let mut tasks = Vec::with_capacity(quantity as usize);
 
-            let result = self.automate_signup(&driver).await;
+        for i in 0..quantity {
+            let server_url = Arc::clone(&server_url);
+            let config = Arc::clone(&config);
+            let browser = self.browser;
+            let locale = self.locale;
+            let semaphore = Arc::clone(&semaphore);
+            let tx = tx.clone();
+
+            tasks.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+                let start = Instant::now();
+
+                info!("Creating account {} of {}...", i + 1, quantity);
+
+                let result: Result<Account> = async {
+                    let profile = BrowserProfile::for_account(&config, i as usize);
+                    let temp_profile = TempProfile::create(browser)?;
+                    let caps = browser.build_capabilities(&config, &profile, &temp_profile.path)?;
+                    let identity = locale.generate_identity();
+                    let driver = WebDriver::new(server_url.as_str(), caps).await?;
+                    let outcome = automate_signup(&config, locale, &identity, &driver).await;
+                    driver.quit().await?;
+                    outcome
+                }
+                .await;
+
+                match result {
+                    Ok(account) => {
+                        info!(
+                            "Account generated successfully: {}:{}. Took {:?}s",
+                            account.email,
+                            account.password,
+                            start.elapsed().as_secs_f32()
+                        );
+
+                        let _ = tx.send(account);
+                    }
+                    Err(err) => error!("Failed to generate account: {}", err),
+                }
+            }));
+        }
 
-            match result {
-                Ok(account) => {
-                    info!(
-                        "Account generated successfully: {}:{}. Took {:?}s",
-                        account.email,
-                        account.password,
-                        start.elapsed().as_secs_f32()
-                    );
+        drop(tx);
 
-                    self.accounts.push(account);
-                }
-                Err(err) => error!("Failed to generate account: {}", err),
-            };
+        for task in tasks {
+            if let Err(err) = task.await {
+                error!("signup task panicked: {}", err);
+            }
+        }
 
-            driver.quit().await?;
+        while let Some(account) = rx.recv().await {
+            self.accounts.push(account);
         }
 
-        info!("Killing chromedriver...");
-        chromedriver.kill()?;
+        info!("Killing {:?} driver...", self.browser);
+        drop(driver_process);
 
         Ok(())
     }
@@ -115,16 +190,43 @@ impl AccountGenerator {
         let file_name = Local::now().format("%d-%m-%Y").to_string();
         let mut path = PathBuf::from(output_path.unwrap_or("./"));
         path.push(file_name);
-        path.set_extension("txt");
 
-        let mut file = fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .append(true)
-            .open(path.clone())?;
+        match self.config.output_format.to_lowercase().as_str() {
+            "json" => {
+                path.set_extension("json");
+
+                let file = fs::OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .truncate(true)
+                    .open(&path)?;
+
+                serde_json::to_writer_pretty(file, &self.accounts)?;
+            }
+            "csv" => {
+                path.set_extension("csv");
+
+                let mut writer = csv::Writer::from_path(&path)?;
+
+                for account in &self.accounts {
+                    writer.serialize(account)?;
+                }
+
+                writer.flush()?;
+            }
+            _ => {
+                path.set_extension("txt");
+
+                let mut file = fs::OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .append(true)
+                    .open(&path)?;
 
-        for account in &self.accounts {
-            writeln!(file, "{}:{}", account.email, account.password)?;
+                for account in &self.accounts {
+                    writeln!(file, "{}:{}", account.email, account.password)?;
+                }
+            }
         }
 
         Ok(path.as_os_str().to_str().unwrap().to_string())
@@ -140,141 +242,135 @@ impl AccountGenerator {
         Ok(config)
     }
 
-    fn run_chromedriver(&self, driver_path: String) -> Result<Child> {
-        let chromedriver = std::process::Command::new(driver_path)
-            .arg("--ip=localhost")
-            .arg(format!("--port={}", &self.config.chromedriver_port))
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
-            .spawn()?;
-
-        Ok(chromedriver)
-    }
-
-    fn get_driver_path() -> Result<String> {
-        info!("Grabbing chromedriver...");
-        let driver_name: String = "chromedriver".to_string();
-
-        let mut selenium_manager = get_manager_by_driver(driver_name).unwrap();
-
-        let path = selenium_manager.resolve_driver().unwrap();
-
-        Ok(path.as_os_str().to_str().unwrap().to_string())
-    }
-
-    fn get_caps(&self) -> Result<ChromeCapabilities> {
-        let mut caps = DesiredCapabilities::chrome();
+}
 
-        caps.add_chrome_arg("--window-size=1920,1080")?;
-        caps.add_chrome_arg("--start-maximized")?;
-        caps.add_chrome_arg(format!("--user-agent={}", USER_AGENT).as_str())?;
+/// Drives a single signup flow to completion. Takes `config`/`identity` by reference
+/// rather than as a method on `AccountGenerator` so it can be shared across concurrent
+/// worker tasks.
+async fn automate_signup(
+    config: &Configuration,
+    locale: Locale,
+    identity: &Identity,
+    driver: &WebDriver,
+) -> Result<Account> {
+    driver.goto(DOORDASH_URL).await?;
+
+    // First name
+    driver
+        .query(By::Css(
+            "input[data-anchor-id=IdentitySignupFirstNameField]",
+        ))
+        .first()
+        .await?
+        .send_keys(&identity.first_name)
+        .await?;
+
+    // Last name
+    driver
+        .query(By::Css("input[data-anchor-id=IdentitySignupLastNameField]"))
+        .first()
+        .await?
+        .send_keys(&identity.last_name)
+        .await?;
+
+    // Email
+    let email = format!(
+        "{}+{}@{}",
+        config.email_name,
+        thread_rng().gen_range(1000000000..10000000000i64),
+        config.email_domain
+    );
+
+    driver
+        .query(By::Css("input[data-anchor-id=IdentitySignupEmailField]"))
+        .first()
+        .await?
+        .send_keys(&email)
+        .await?;
+
+    // Country code
+    driver
+        .query(By::Css("#FieldWrapper-3"))
+        .first()
+        .await?
+        .find(By::XPath(format!(
+            "option[@value='{}']",
+            locale.country_code_option()
+        )))
+        .await?
+        .click()
+        .await?;
+
+    // Phone number
+    driver
+        .query(By::Css("input[data-anchor-id=IdentitySignupPhoneField]"))
+        .first()
+        .await?
+        .send_keys(&identity.phone_number)
+        .await?;
+
+    // Password
+    driver
+        .query(By::Css("input[data-anchor-id=IdentitySignupPasswordField]"))
+        .first()
+        .await?
+        .send_keys(&config.password)
+        .await?;
+
+    // Submit
+    driver
+        .query(By::Css("button[data-anchor-id=IdentitySignupSubmitButton]"))
+        .first()
+        .await?
+        .click()
+        .await?;
+
+    // Address
+    driver
+        .query(By::Css("input[aria-label='Your delivery address']"))
+        .first()
+        .await?
+        .send_keys(&config.address)
+        .await?;
+
+    driver
+        .query(By::Css(
+            "span[data-anchor-id=AddressAutocompleteSuggestion-0]",
+        ))
+        .first()
+        .await?
+        .click()
+        .await?;
+
+    Ok(Account {
+        email,
+        password: config.password.clone(),
+        first_name: identity.first_name.clone(),
+        last_name: identity.last_name.clone(),
+        phone_number: identity.phone_number.clone(),
+        locale: locale.country_code_option().to_string(),
+        created: Local::now(),
+    })
+}
 
-        if self.config.headless.unwrap_or(true) {
-            caps.add_chrome_arg("--headless")?;
-        };
+fn default_port_range() -> (u16, u16) {
+    (9515, 9615)
+}
 
-        Ok(caps)
-    }
+fn default_browser() -> String {
+    "chrome".to_string()
+}
 
-    async fn automate_signup(&self, driver: &WebDriver) -> Result<Account> {
-        driver.goto(DOORDASH_URL).await?;
-
-        // First name
-        driver
-            .query(By::Css(
-                "input[data-anchor-id=IdentitySignupFirstNameField]",
-            ))
-            .first()
-            .await?
-            .send_keys(&self.config.first_name)
-            .await?;
-
-        // Last name
-        driver
-            .query(By::Css("input[data-anchor-id=IdentitySignupLastNameField]"))
-            .first()
-            .await?
-            .send_keys(&self.config.last_name)
-            .await?;
-
-        // Email
-        let email = format!(
-            "{}+{}@{}",
-            self.config.email_name,
-            thread_rng().gen_range(1000000000..10000000000i64),
-            self.config.email_domain
-        );
+fn default_concurrency() -> usize {
+    1
+}
 
-        driver
-            .query(By::Css("input[data-anchor-id=IdentitySignupEmailField]"))
-            .first()
-            .await?
-            .send_keys(&email)
-            .await?;
-
-        // Country code
-        driver
-            .query(By::Css("#FieldWrapper-3"))
-            .first()
-            .await?
-            .find(By::XPath("option[@value='AU']"))
-            .await?
-            .click()
-            .await?;
-
-        // Phone number
-        let phone_number = format!("0452{}", thread_rng().gen_range(100000..1000000));
-
-        driver
-            .query(By::Css("input[data-anchor-id=IdentitySignupPhoneField]"))
-            .first()
-            .await?
-            .send_keys(&phone_number)
-            .await?;
-
-        // Password
-        driver
-            .query(By::Css("input[data-anchor-id=IdentitySignupPasswordField]"))
-            .first()
-            .await?
-            .send_keys(&self.config.password)
-            .await?;
-
-        // Submit
-        driver
-            .query(By::Css("button[data-anchor-id=IdentitySignupSubmitButton]"))
-            .first()
-            .await?
-            .click()
-            .await?;
-
-        // Address
-        driver
-            .query(By::Css("input[aria-label='Your delivery address']"))
-            .first()
-            .await?
-            .send_keys(&self.config.address)
-            .await?;
-
-        driver
-            .query(By::Css(
-                "span[data-anchor-id=AddressAutocompleteSuggestion-0]",
-            ))
-            .first()
-            .await?
-            .click()
-            .await?;
-
-        Ok(Account {
-            email,
-            password: self.config.password.clone(),
-            created: Local::now(),
-        })
-    }
+fn default_locale() -> String {
+    "AU".to_string()
 }
 
-fn default_port() -> i32 {
-    9515
+fn default_output_format() -> String {
+    "txt".to_string()
 }
 
 fn generate_password() -> String {
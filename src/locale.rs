@@ -0,0 +1,91 @@
+use anyhow::{anyhow, Result};
+use rand::{seq::SliceRandom, thread_rng, Rng};
+
+/// The market a batch of accounts is generated for. Drives both the identity
+/// generator and the country-code option the signup form expects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    Au,
+    Us,
+}
+
+/// A freshly generated name and phone number for a single account.
+pub struct Identity {
+    pub first_name: String,
+    pub last_name: String,
+    pub phone_number: String,
+}
+
+impl Locale {
+    pub fn from_config(name: &str) -> Result<Self> {
+        match name.to_uppercase().as_str() {
+            "AU" => Ok(Self::Au),
+            "US" => Ok(Self::Us),
+            other => Err(anyhow!(
+                "unsupported locale '{}', expected 'AU' or 'US'",
+                other
+            )),
+        }
+    }
+
+    /// The `option[@value=...]` the signup form's country-code dropdown expects.
+    pub fn country_code_option(&self) -> &'static str {
+        match self {
+            Self::Au => "AU",
+            Self::Us => "US",
+        }
+    }
+
+    pub fn generate_identity(&self) -> Identity {
+        let mut rng = thread_rng();
+
+        let first_name = (*self.first_names().choose(&mut rng).unwrap()).to_string();
+        let last_name = (*self.last_names().choose(&mut rng).unwrap()).to_string();
+        let phone_number = self.generate_phone_number(&mut rng);
+
+        Identity {
+            first_name,
+            last_name,
+            phone_number,
+        }
+    }
+
+    fn first_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Au => &[
+                "Olivia", "Jack", "Charlotte", "William", "Amelia", "Noah", "Mia", "Oliver",
+                "Isla", "Leo",
+            ],
+            Self::Us => &[
+                "Emma", "Liam", "Ava", "James", "Sophia", "Benjamin", "Isabella", "Lucas",
+                "Mia", "Henry",
+            ],
+        }
+    }
+
+    fn last_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Au => &[
+                "Smith", "Jones", "Williams", "Brown", "Wilson", "Taylor", "Nguyen", "Anderson",
+                "Kelly", "Ryan",
+            ],
+            Self::Us => &[
+                "Smith", "Johnson", "Williams", "Brown", "Jones", "Garcia", "Miller", "Davis",
+                "Martinez", "Wilson",
+            ],
+        }
+    }
+
+    fn generate_phone_number(&self, rng: &mut impl Rng) -> String {
+        match self {
+            // Australian mobile numbers: 04 followed by 8 digits.
+            Self::Au => format!("04{}", rng.gen_range(10_000_000..100_000_000u32)),
+            // US numbers: a 3-digit area code followed by a 7-digit subscriber number.
+            Self::Us => format!(
+                "{}{}",
+                rng.gen_range(200..1000u32),
+                rng.gen_range(1_000_000..10_000_000u32)
+            ),
+        }
+    }
+}
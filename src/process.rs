@@ -0,0 +1,89 @@
+use anyhow::Result;
+use std::io::{BufRead, BufReader};
+use std::net::TcpStream;
+use std::process::Child;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::browser::Browser;
+
+const READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Error)]
+pub enum DriverLaunchError {
+    #[error("no free port found in range {0}-{1}")]
+    DebugPortInUse(u16, u16),
+    #[error("timed out after {1:?} waiting for the driver to start listening on port {0}")]
+    PortOpenTimeout(u16, Duration),
+}
+
+/// A running driver child process bound to `port`. Killed automatically on drop,
+/// so it is cleaned up on every exit path, including an early return or panic.
+pub struct DriverProcess {
+    child: Child,
+    pub port: u16,
+}
+
+impl DriverProcess {
+    /// Picks a free port in `port_range`, spawns `browser`'s driver on it, and
+    /// blocks until the WebDriver endpoint is actually accepting connections.
+    pub fn launch(browser: Browser, driver_path: &str, port_range: (u16, u16)) -> Result<Self> {
+        let port = find_free_port(port_range)?;
+
+        let mut child = browser.driver_command(driver_path, port).spawn()?;
+
+        if let Err(err) = wait_until_ready(&mut child, port) {
+            let _ = child.kill();
+            return Err(err);
+        }
+
+        info!("driver listening on port {}", port);
+
+        Ok(Self { child, port })
+    }
+}
+
+impl Drop for DriverProcess {
+    fn drop(&mut self) {
+        if let Err(err) = self.child.kill() {
+            warn!("failed to kill driver process: {}", err);
+        }
+    }
+}
+
+fn find_free_port(range: (u16, u16)) -> Result<u16> {
+    let (start, end) = range;
+
+    (start..=end)
+        .find(|port| TcpStream::connect(("127.0.0.1", *port)).is_err())
+        .ok_or_else(|| DriverLaunchError::DebugPortInUse(start, end).into())
+}
+
+fn wait_until_ready(child: &mut Child, port: u16) -> Result<()> {
+    let deadline = Instant::now() + READY_TIMEOUT;
+
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+
+    if let Some(stdout) = child.stdout.take() {
+        // Read on a separate thread so a driver that never prints the readiness
+        // line cannot block past the deadline.
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(|line| line.ok()) {
+                if line.to_lowercase().contains("listening on port") {
+                    let _ = ready_tx.send(());
+                }
+            }
+        });
+    }
+
+    while Instant::now() < deadline {
+        if ready_rx.try_recv().is_ok() || TcpStream::connect(("127.0.0.1", port)).is_ok() {
+            return Ok(());
+        }
+
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    Err(DriverLaunchError::PortOpenTimeout(port, READY_TIMEOUT).into())
+}
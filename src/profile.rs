@@ -0,0 +1,80 @@
+use anyhow::Result;
+use rand::{thread_rng, Rng};
+use std::{env, fs, path::PathBuf};
+use tracing::warn;
+
+use crate::browser::Browser;
+
+/// A unique, disposable browser profile directory for a single signup session, so
+/// cookies/localStorage from one account can't bleed into the next. Removed
+/// automatically on drop, so it is cleaned up on every exit path.
+pub struct TempProfile {
+    pub path: PathBuf,
+}
+
+impl TempProfile {
+    pub fn create(browser: Browser) -> Result<Self> {
+        let mut path = profile_root(browser);
+        path.push(format!("session-{:x}", thread_rng().gen::<u64>()));
+
+        fs::create_dir_all(&path)?;
+
+        Ok(Self { path })
+    }
+}
+
+impl Drop for TempProfile {
+    fn drop(&mut self) {
+        if let Err(err) = fs::remove_dir_all(&self.path) {
+            warn!("failed to remove temp profile {}: {}", self.path.display(), err);
+        }
+    }
+}
+
+/// Mirrors each browser's real profile root per platform, so generated profiles live
+/// alongside genuine ones (under a `doordash-gen` subdirectory) rather than an
+/// arbitrary scratch path.
+fn profile_root(browser: Browser) -> PathBuf {
+    let mut path = match browser {
+        Browser::Chrome => {
+            if cfg!(target_os = "windows") {
+                PathBuf::from(env::var("LOCALAPPDATA").unwrap_or_default())
+                    .join("Google")
+                    .join("Chrome")
+                    .join("User Data")
+            } else if cfg!(target_os = "macos") {
+                home_dir()
+                    .join("Library")
+                    .join("Application Support")
+                    .join("Google")
+                    .join("Chrome")
+            } else {
+                home_dir().join(".config").join("google-chrome")
+            }
+        }
+        Browser::Firefox => {
+            if cfg!(target_os = "windows") {
+                PathBuf::from(env::var("APPDATA").unwrap_or_default())
+                    .join("Mozilla")
+                    .join("Firefox")
+                    .join("Profiles")
+            } else if cfg!(target_os = "macos") {
+                home_dir()
+                    .join("Library")
+                    .join("Application Support")
+                    .join("Firefox")
+                    .join("Profiles")
+            } else {
+                home_dir().join(".mozilla").join("firefox")
+            }
+        }
+    };
+
+    path.push("doordash-gen");
+
+    path
+}
+
+fn home_dir() -> PathBuf {
+    env::var("HOME").map(PathBuf::from).unwrap_or_default()
+}